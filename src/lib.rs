@@ -18,5 +18,7 @@ pub mod stats;
 
 pub use mojang_error::MojangError;
 pub use player::Player;
-pub use server_block::BlockedServers;
+pub use server_block::{
+    classify, AddressScope, BlockedServers, IpFilter, ResolvedBlockReport, ResolvedMatch,
+};
 pub use stats::{MetricKeys, Stats};
@@ -1,10 +1,117 @@
 use std::borrow::Cow;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 
+use ipnetwork::{IpNetwork, Ipv4Network, Ipv6Network};
 use sha1::{Digest, Sha1};
+use trust_dns_resolver::Resolver;
 
 use crate::common;
 use crate::MojangError;
 
+/// The IANA special-purpose scope of an address.
+///
+/// Only [`AddressScope::Global`] addresses can appear in Mojang's public blocklist, so
+/// [`BlockedServers::find_blocked_pattern`] uses this to short-circuit everything else.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AddressScope {
+    /// A publicly routable address, or a hostname (which is assumed global until resolved).
+    Global,
+    /// A private-use address, e.g. `10.0.0.0/8` or the IPv6 unique-local range `fc00::/7`.
+    Private,
+    /// A loopback address, e.g. `127.0.0.0/8` or `::1`.
+    Loopback,
+    /// A link-local address, e.g. `169.254.0.0/16` or `fe80::/10`.
+    LinkLocal,
+    /// The unspecified address `::`, or any address in `0.0.0.0/8` ("this host on this network").
+    Unspecified,
+    /// Any other IANA special-purpose range, e.g. NAT64/6to4/documentation ranges.
+    Reserved,
+}
+
+/// Classify `address` per the IANA special-purpose address registries.
+///
+/// Hostnames (anything that doesn't parse as a literal [`IpAddr`]) are classified as
+/// [`AddressScope::Global`], since only Mojang's blocklist can tell us anything more about them.
+/// ## Example
+/// ```rust
+/// # use mojang::server_block::{classify, AddressScope};
+/// assert_eq!(classify("mc.hypixel.net"), AddressScope::Global);
+/// assert_eq!(classify("8.8.8.8"), AddressScope::Global);
+///
+/// assert_eq!(classify("10.0.0.1"), AddressScope::Private);
+/// assert_eq!(classify("100.64.0.1"), AddressScope::Private);
+/// assert_eq!(classify("172.16.0.1"), AddressScope::Private);
+/// assert_eq!(classify("192.168.1.1"), AddressScope::Private);
+///
+/// assert_eq!(classify("127.0.0.1"), AddressScope::Loopback);
+/// assert_eq!(classify("169.254.1.1"), AddressScope::LinkLocal);
+/// assert_eq!(classify("0.0.0.0"), AddressScope::Unspecified);
+/// assert_eq!(classify("192.0.0.1"), AddressScope::Reserved);
+/// assert_eq!(classify("240.0.0.1"), AddressScope::Reserved);
+///
+/// assert_eq!(classify("::1"), AddressScope::Loopback);
+/// assert_eq!(classify("::"), AddressScope::Unspecified);
+/// assert_eq!(classify("fc00::1"), AddressScope::Private);
+/// assert_eq!(classify("fe80::1"), AddressScope::LinkLocal);
+/// assert_eq!(classify("2001:db8::1"), AddressScope::Reserved);
+/// ```
+pub fn classify(address: &str) -> AddressScope {
+    match address.parse::<IpAddr>() {
+        Ok(IpAddr::V4(ip)) => classify_ipv4(ip),
+        Ok(IpAddr::V6(ip)) => classify_ipv6(ip),
+        Err(_) => AddressScope::Global,
+    }
+}
+
+/// Hardcoded IANA IPv4 special-purpose ranges, see <https://www.iana.org/assignments/iana-ipv4-special-registry>.
+const IPV4_SPECIAL_RANGES: &[(Ipv4Addr, u8, AddressScope)] = &[
+    (Ipv4Addr::new(0, 0, 0, 0), 8, AddressScope::Unspecified), // "This host on this network"
+    (Ipv4Addr::new(10, 0, 0, 0), 8, AddressScope::Private),
+    (Ipv4Addr::new(100, 64, 0, 0), 10, AddressScope::Private), // Shared Address Space (carrier-grade NAT)
+    (Ipv4Addr::new(127, 0, 0, 0), 8, AddressScope::Loopback),
+    (Ipv4Addr::new(169, 254, 0, 0), 16, AddressScope::LinkLocal),
+    (Ipv4Addr::new(172, 16, 0, 0), 12, AddressScope::Private),
+    (Ipv4Addr::new(192, 0, 0, 0), 24, AddressScope::Reserved), // IETF Protocol Assignments
+    (Ipv4Addr::new(192, 0, 2, 0), 24, AddressScope::Reserved), // TEST-NET-1
+    (Ipv4Addr::new(192, 88, 99, 0), 24, AddressScope::Reserved), // formerly 6to4 relay anycast
+    (Ipv4Addr::new(192, 168, 0, 0), 16, AddressScope::Private),
+    (Ipv4Addr::new(198, 18, 0, 0), 15, AddressScope::Reserved), // benchmarking
+    (Ipv4Addr::new(198, 51, 100, 0), 24, AddressScope::Reserved), // TEST-NET-2
+    (Ipv4Addr::new(203, 0, 113, 0), 24, AddressScope::Reserved), // TEST-NET-3
+    (Ipv4Addr::new(224, 0, 0, 0), 4, AddressScope::Reserved),  // multicast
+    (Ipv4Addr::new(240, 0, 0, 0), 4, AddressScope::Reserved),  // reserved for future use
+    (Ipv4Addr::new(255, 255, 255, 255), 32, AddressScope::Reserved), // limited broadcast
+];
+
+/// Hardcoded IANA IPv6 special-purpose ranges, see <https://www.iana.org/assignments/iana-ipv6-special-registry>.
+const IPV6_SPECIAL_RANGES: &[(Ipv6Addr, u8, AddressScope)] = &[
+    (Ipv6Addr::new(0, 0, 0, 0, 0, 0, 0, 0), 128, AddressScope::Unspecified),
+    (Ipv6Addr::new(0, 0, 0, 0, 0, 0, 0, 1), 128, AddressScope::Loopback),
+    (Ipv6Addr::new(0x100, 0, 0, 0, 0, 0, 0, 0), 64, AddressScope::Reserved), // discard-only
+    (Ipv6Addr::new(0x64, 0xff9b, 0, 0, 0, 0, 0, 0), 96, AddressScope::Reserved), // NAT64
+    (Ipv6Addr::new(0x2001, 0, 0, 0, 0, 0, 0, 0), 23, AddressScope::Reserved), // IETF Protocol Assignments
+    (Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0), 32, AddressScope::Reserved), // documentation
+    (Ipv6Addr::new(0x2002, 0, 0, 0, 0, 0, 0, 0), 16, AddressScope::Reserved), // 6to4
+    (Ipv6Addr::new(0xfc00, 0, 0, 0, 0, 0, 0, 0), 7, AddressScope::Private),  // unique local
+    (Ipv6Addr::new(0xfe80, 0, 0, 0, 0, 0, 0, 0), 10, AddressScope::LinkLocal),
+];
+
+fn classify_ipv4(ip: Ipv4Addr) -> AddressScope {
+    IPV4_SPECIAL_RANGES
+        .iter()
+        .find(|(base, prefix, _)| Ipv4Network::new(*base, *prefix).unwrap().contains(ip))
+        .map(|(_, _, scope)| *scope)
+        .unwrap_or(AddressScope::Global)
+}
+
+fn classify_ipv6(ip: Ipv6Addr) -> AddressScope {
+    IPV6_SPECIAL_RANGES
+        .iter()
+        .find(|(base, prefix, _)| Ipv6Network::new(*base, *prefix).unwrap().contains(ip))
+        .map(|(_, _, scope)| *scope)
+        .unwrap_or(AddressScope::Global)
+}
+
 /// Info on all Mojang Blocked Servers
 /// ## Example
 /// ```rust
@@ -65,17 +172,27 @@ impl BlockedServers {
     ///
     /// // Find the matching pattern
     /// assert_eq!(blocked.find_blocked_pattern("mc.example.com"), Some(Cow::from("*.example.com")));
-    /// assert_eq!(blocked.find_blocked_pattern("192.0.2.235"), Some(Cow::from("192.0.*")));
-    /// assert_eq!(blocked.find_blocked_pattern("127.0.0.1"), Some(Cow::from("127.0.0.1")));
-    /// assert_eq!(blocked.find_blocked_pattern("127.0.0.2"), None);
+    /// assert_eq!(blocked.find_blocked_pattern("192.0.5.235"), Some(Cow::from("192.0.*")));
+    ///
+    /// // 127.0.0.1 is a loopback address, so it's never checked even though its hash is listed above:
+    /// // it can't appear in Mojang's public blocklist in the first place.
+    /// assert_eq!(blocked.find_blocked_pattern("127.0.0.1"), None);
     /// ```
     pub fn find_blocked_pattern<'a>(&self, address: &'a str) -> Option<Cow<'a, str>> {
-        let address_parts: Vec<&str> = address.split('.').collect();
+        if classify(address) != AddressScope::Global {
+            return None;
+        }
 
-        if self.is_pattern_blocked(&address) {
+        if self.is_pattern_blocked(address) {
             return Some(Cow::Borrowed(address));
         }
 
+        if address.contains(':') {
+            return self.find_blocked_ipv6_pattern(address);
+        }
+
+        let address_parts: Vec<&str> = address.split('.').collect();
+
         if is_ipv4(&address_parts) {
             (1..address_parts.len())
                 .rev()
@@ -90,6 +207,40 @@ impl BlockedServers {
         }
     }
 
+    /// Check an IPv6 literal against the blocklist.
+    ///
+    /// We normalize `address` to its canonical lowercase, RFC 5952 compressed form (what
+    /// [`Ipv6Addr`]'s `Display` produces) and test that directly, then fall back to
+    /// right-truncated hextet wildcards (`2001:db8:*`), mirroring the octet truncation already
+    /// done for IPv4 in [`find_blocked_pattern`](Self::find_blocked_pattern).
+    ///
+    /// This assumes Mojang hashes the same RFC 5952 form the vanilla Java client's
+    /// `InetAddress` would produce; that hasn't been confirmed against a real blocked IPv6
+    /// entry (the public blocklist doesn't appear to contain any), so treat this as best-effort
+    /// until it can be checked against one.
+    fn find_blocked_ipv6_pattern<'a>(&self, address: &'a str) -> Option<Cow<'a, str>> {
+        let address_parts: Vec<&str> = address.split(':').collect();
+
+        if !is_ipv6(&address_parts) {
+            return None;
+        }
+
+        let ip: Ipv6Addr = address.parse().ok()?;
+
+        let canonical = ip.to_string();
+        if self.is_pattern_blocked(&canonical) {
+            return Some(Cow::Owned(canonical));
+        }
+
+        let hextets: Vec<String> = ip.segments().iter().map(|s| format!("{:x}", s)).collect();
+
+        (1..hextets.len())
+            .rev()
+            .map(|i| format!("{}:*", hextets[..i].join(":")))
+            .find(|pattern| self.is_pattern_blocked(pattern))
+            .map(Cow::Owned)
+    }
+
     /// Check if the supplied address is in the blocklist
     /// ## Example
     /// ```rust
@@ -104,12 +255,253 @@ impl BlockedServers {
         self.find_blocked_pattern(address).is_some()
     }
 
+    /// Resolve `hostname` to its A/AAAA records and check the hostname, along with every
+    /// resolved address, against the blocklist.
+    ///
+    /// Minecraft clients resolve a hostname before connecting, so a hostname whose own pattern
+    /// is unblocked can still resolve to a blocked IP (and a blocked-looking hostname could
+    /// resolve to an address that isn't actually blocked). For each blocked IP this also runs a
+    /// forward-confirmed reverse DNS check: it takes the PTR record, re-resolves that name, and
+    /// notes whether the original IP came back, which helps callers spot spoofed or stale PTR data.
+    ///
+    /// DNS failures (an unresolvable hostname, no system resolver, ...) are not reported as an
+    /// error: the report then holds only the hostname's own match, if any, since "no records" and
+    /// "resolution failed" both just mean there's nothing more than the hostname itself to check.
+    /// ## Example
+    /// ```rust
+    /// # use mojang::BlockedServers;
+    /// let blocked = BlockedServers { hashes: vec![] };
+    /// let report = blocked.is_blocked_resolved("mc.playmc.mx");
+    /// assert!(!report.is_blocked());
+    /// ```
+    pub fn is_blocked_resolved(&self, hostname: &str) -> ResolvedBlockReport {
+        let mut matches = Vec::new();
+
+        if let Some(pattern) = self.find_blocked_pattern(hostname) {
+            matches.push(ResolvedMatch {
+                address: hostname.to_string(),
+                pattern: pattern.into_owned(),
+                fcrdns_confirmed: None,
+            });
+        }
+
+        let resolver = match Resolver::from_system_conf() {
+            Ok(resolver) => resolver,
+            Err(_) => return ResolvedBlockReport { matches },
+        };
+
+        let lookup = match resolver.lookup_ip(hostname) {
+            Ok(lookup) => lookup,
+            Err(_) => return ResolvedBlockReport { matches },
+        };
+
+        for ip in lookup.iter() {
+            let address = ip.to_string();
+
+            let pattern = match self.find_blocked_pattern(&address) {
+                Some(pattern) => pattern.into_owned(),
+                None => continue,
+            };
+
+            matches.push(ResolvedMatch {
+                address,
+                pattern,
+                fcrdns_confirmed: Self::confirm_fcrdns(&resolver, ip),
+            });
+        }
+
+        ResolvedBlockReport { matches }
+    }
+
+    /// Forward-confirm that `ip`'s PTR record resolves back to an address containing `ip`.
+    ///
+    /// Returns `None` when there's no PTR record to check in the first place (common and
+    /// benign for ordinary residential/hosting IPs), as opposed to `Some(false)`, which means a
+    /// PTR record exists but its forward lookup didn't return `ip` — the actual spoofing signal.
+    fn confirm_fcrdns(resolver: &Resolver, ip: IpAddr) -> Option<bool> {
+        let names = resolver.reverse_lookup(ip).ok()?;
+
+        if names.iter().next().is_none() {
+            return None;
+        }
+
+        Some(names.iter().any(|name| {
+            resolver
+                .lookup_ip(name.to_string())
+                .map(|forward| forward.iter().any(|resolved| resolved == ip))
+                .unwrap_or(false)
+        }))
+    }
+
     fn is_pattern_blocked(&self, pattern: &str) -> bool {
         let hash = format!("{:#02X}", Sha1::digest(pattern.as_bytes())).to_lowercase();
         self.hashes.contains(&hash)
     }
 }
 
+/// One resolved address that matched a blocklist pattern, as part of a [`ResolvedBlockReport`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResolvedMatch {
+    /// The address that was checked against the blocklist: either the original hostname, or
+    /// one of its resolved IPs.
+    pub address: String,
+    /// The blocklist pattern that `address` matched.
+    pub pattern: String,
+    /// Whether forward-confirmed reverse DNS verified `address`.
+    ///
+    /// `None` when `address` is the original hostname (FCrDNS only applies to resolved IPs), or
+    /// when the PTR lookup itself failed; `Some(false)` means the PTR record's forward lookup
+    /// didn't return `address`, which can indicate a spoofed or stale PTR record.
+    pub fcrdns_confirmed: Option<bool>,
+}
+
+/// The result of [`BlockedServers::is_blocked_resolved`].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ResolvedBlockReport {
+    /// Every address (the original hostname and/or its resolved IPs) that matched the blocklist.
+    pub matches: Vec<ResolvedMatch>,
+}
+
+impl ResolvedBlockReport {
+    /// Whether any checked address matched the blocklist.
+    /// ## Example
+    /// ```rust
+    /// # use mojang::server_block::ResolvedBlockReport;
+    /// assert!(!ResolvedBlockReport::default().is_blocked());
+    /// ```
+    pub fn is_blocked(&self) -> bool {
+        !self.matches.is_empty()
+    }
+}
+
+/// Layers custom allow/block CIDR rules over a [`BlockedServers`] list.
+///
+/// Block rules always win, then allow rules, then the base Mojang blocklist. This lets
+/// applications enforce their own policy on top of Mojang's hashed list without a second
+/// lookup pass.
+/// ## Example
+/// ```rust
+/// # use mojang::server_block::IpFilter;
+/// use ipnetwork::IpNetwork;
+///
+/// // Run purely on a custom block range, with no base Mojang blocklist.
+/// let filter = IpFilter::new(None, vec![], vec!["10.0.0.0/8".parse::<IpNetwork>().unwrap()]);
+///
+/// assert!(filter.is_blocked("10.1.2.3"));
+/// assert!(!filter.is_blocked("8.8.8.8"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct IpFilter {
+    /// The base Mojang blocklist to fall back to, if any.
+    pub base: Option<BlockedServers>,
+    /// Ranges that are always allowed, regardless of `base`.
+    pub allow: Vec<IpNetwork>,
+    /// Ranges that are always blocked, regardless of `base` or `allow`.
+    pub block: Vec<IpNetwork>,
+}
+
+impl IpFilter {
+    /// Build a filter from a base blocklist and custom CIDR ranges.
+    ///
+    /// Pass `None` for `base` to run purely on the custom `allow`/`block` ranges.
+    /// ## Example
+    /// ```rust
+    /// # use mojang::server_block::IpFilter;
+    /// let filter = IpFilter::new(None, vec![], vec![]);
+    /// ```
+    pub fn new(base: Option<BlockedServers>, allow: Vec<IpNetwork>, block: Vec<IpNetwork>) -> IpFilter {
+        IpFilter { base, allow, block }
+    }
+
+    /// Check if the supplied address is blocked by either the custom rules or `base`.
+    /// ## Example
+    /// ```rust
+    /// # use mojang::server_block::IpFilter;
+    /// use ipnetwork::IpNetwork;
+    ///
+    /// let filter = IpFilter::new(
+    ///     None,
+    ///     vec!["10.1.0.0/16".parse::<IpNetwork>().unwrap()],
+    ///     vec!["10.0.0.0/8".parse::<IpNetwork>().unwrap()],
+    /// );
+    ///
+    /// // The allow range is more specific, but block rules always take precedence.
+    /// assert!(filter.is_blocked("10.1.2.3"));
+    /// ```
+    pub fn is_blocked(&self, address: &str) -> bool {
+        if let Ok(ip) = address.parse::<IpAddr>() {
+            if self.block.iter().any(|net| net.contains(ip)) {
+                return true;
+            }
+
+            if self.allow.iter().any(|net| net.contains(ip)) {
+                return false;
+            }
+        }
+
+        self.base
+            .as_ref()
+            .map(|base| base.is_blocked(address))
+            .unwrap_or(false)
+    }
+}
+
+#[cfg(test)]
+mod ip_filter_tests {
+    use super::*;
+
+    fn network(cidr: &str) -> IpNetwork {
+        cidr.parse().unwrap()
+    }
+
+    #[test]
+    fn block_takes_precedence_over_allow() {
+        let filter = IpFilter::new(None, vec![network("10.1.0.0/16")], vec![network("10.0.0.0/8")]);
+
+        assert!(filter.is_blocked("10.1.2.3"));
+    }
+
+    #[test]
+    fn allow_overrides_base_when_there_is_no_block_match() {
+        let base = BlockedServers {
+            hashes: vec![format!("{:#02X}", Sha1::digest(b"8.8.8.8")).to_lowercase()],
+        };
+        assert!(base.is_blocked("8.8.8.8"));
+
+        let filter = IpFilter::new(Some(base), vec![network("8.0.0.0/8")], vec![]);
+
+        assert!(!filter.is_blocked("8.8.8.8"));
+    }
+
+    #[test]
+    fn empty_filter_never_blocks() {
+        let filter = IpFilter::new(None, vec![], vec![]);
+
+        assert!(!filter.is_blocked("10.1.2.3"));
+        assert!(!filter.is_blocked("8.8.8.8"));
+        assert!(!filter.is_blocked("mc.example.com"));
+    }
+
+    #[test]
+    fn hostnames_fall_through_to_base_untouched_by_cidr_rules() {
+        let base = BlockedServers {
+            hashes: vec!["8c7122d652cb7be22d1986f1f30b07fd5108d9c0".to_string()], // *.example.com
+        };
+        let filter = IpFilter::new(Some(base), vec![network("10.0.0.0/8")], vec![network("0.0.0.0/0")]);
+
+        assert!(filter.is_blocked("mc.example.com"));
+        assert!(!filter.is_blocked("mc.other.com"));
+    }
+
+    #[test]
+    fn runs_purely_on_custom_ranges_with_no_base() {
+        let filter = IpFilter::new(None, vec![], vec![network("10.0.0.0/8")]);
+
+        assert!(filter.is_blocked("10.1.2.3"));
+        assert!(!filter.is_blocked("8.8.8.8"));
+    }
+}
+
 #[doc(hidden)]
 /// Tests if an address is ipv4 naively to better match how mojang determines if an address is ipv4 or not.
 /// ## Example
@@ -121,4 +513,148 @@ impl BlockedServers {
 pub fn is_ipv4(ip: &[&str]) -> bool {
     // If thare are too many sections, and each octet is a valid u8
     ip.len() == 4 && ip.iter().all(|x| x.parse::<u8>().is_ok())
+}
+
+#[doc(hidden)]
+/// Tests if an address is ipv6 naively to better match how mojang determines if an address is ipv6 or not.
+/// ## Example
+/// ```rust
+/// # use mojang::server_block::is_ipv6;
+/// assert!(!is_ipv6(&["mc", "example", "com"]));
+/// assert!(is_ipv6(&["2001", "db8", "", "1"]));
+/// assert!(is_ipv6(&["", "", "1"]));
+/// ```
+pub fn is_ipv6(ip: &[&str]) -> bool {
+    // A compressed address needs at least one empty group and no more than 8 groups total,
+    // an uncompressed one needs exactly 8.
+    if ip.len() < 3 || ip.len() > 8 {
+        return false;
+    }
+
+    let mut has_compressed_group = false;
+
+    for (i, group) in ip.iter().enumerate() {
+        if group.is_empty() {
+            has_compressed_group = true;
+            continue;
+        }
+
+        // The last group may be an embedded IPv4 tail, e.g. `::ffff:192.0.2.1`.
+        if group.contains('.') {
+            if i != ip.len() - 1 || !is_ipv4(&group.split('.').collect::<Vec<_>>()) {
+                return false;
+            }
+            continue;
+        }
+
+        if group.len() > 4 || !group.chars().all(|c| c.is_ascii_hexdigit()) {
+            return false;
+        }
+    }
+
+    has_compressed_group || ip.len() == 8
+}
+
+#[cfg(test)]
+mod ipv6_pattern_tests {
+    use super::*;
+
+    // Hashes computed independently with `sha1sum` rather than via this crate's own
+    // canonicalization, so these actually exercise match behavior instead of round-tripping
+    // the same RFC 5952 assumption `find_blocked_ipv6_pattern` is built on. These use a real,
+    // globally-routable literal (Cloudflare's 2606:4700:4700::/48) rather than the
+    // 2001:db8::/32 documentation range, which `classify` (correctly) maps to
+    // `AddressScope::Reserved` and would short-circuit `find_blocked_pattern` before the
+    // IPv6 matching logic ever runs.
+    const EXACT_HASH: &str = "92e05f126d01e21f61f8b0eb398e49bf032d828d"; // sha1sum of "2606:4700:4700::1111"
+    const WILDCARD_HASH: &str = "138c9cdaeed862395895ccac1e16e2333a1e6cbe"; // sha1sum of "2606:4700:4700:*"
+
+    #[test]
+    fn matches_exact_canonical_hash_from_an_expanded_literal() {
+        let blocked = BlockedServers {
+            hashes: vec![EXACT_HASH.to_string()],
+        };
+
+        assert_eq!(
+            blocked.find_blocked_pattern("2606:4700:4700:0:0:0:0:1111"),
+            Some(Cow::from("2606:4700:4700::1111"))
+        );
+    }
+
+    #[test]
+    fn matches_truncated_hextet_wildcard_hash() {
+        let blocked = BlockedServers {
+            hashes: vec![WILDCARD_HASH.to_string()],
+        };
+
+        assert_eq!(
+            blocked.find_blocked_pattern("2606:4700:4700::9999"),
+            Some(Cow::from("2606:4700:4700:*"))
+        );
+    }
+}
+
+#[cfg(test)]
+mod classify_tests {
+    use super::*;
+
+    #[test]
+    fn classifies_every_ipv4_special_range() {
+        for &(base, prefix, scope) in IPV4_SPECIAL_RANGES {
+            let network = Ipv4Network::new(base, prefix).unwrap();
+            assert_eq!(
+                classify(&network.network().to_string()),
+                scope,
+                "{network} network address"
+            );
+            assert_eq!(
+                classify(&network.broadcast().to_string()),
+                scope,
+                "{network} broadcast address"
+            );
+        }
+    }
+
+    #[test]
+    fn classifies_every_ipv6_special_range() {
+        for &(base, prefix, scope) in IPV6_SPECIAL_RANGES {
+            let network = Ipv6Network::new(base, prefix).unwrap();
+            assert_eq!(
+                classify(&network.network().to_string()),
+                scope,
+                "{network} network address"
+            );
+            assert_eq!(
+                classify(&network.broadcast().to_string()),
+                scope,
+                "{network} broadcast address"
+            );
+        }
+    }
+
+    #[test]
+    fn classifies_addresses_just_outside_ipv4_ranges_as_global() {
+        // One address past the end of each IPv4 range, skipping ranges that butt up against
+        // another reserved range (224.0.0.0/4 and 240.0.0.0/4 are adjacent, so there's no
+        // "just outside" address for either that isn't itself reserved).
+        let just_outside = [
+            "1.0.0.0",
+            "11.0.0.0",
+            "100.128.0.0",
+            "128.0.0.0",
+            "169.255.0.0",
+            "172.32.0.0",
+            "192.0.1.0",
+            "192.0.3.0",
+            "192.88.100.0",
+            "192.169.0.0",
+            "198.20.0.0",
+            "198.51.101.0",
+            "203.0.114.0",
+        ];
+
+        for address in just_outside {
+            assert_eq!(classify(address), AddressScope::Global, "{address}");
+        }
+    }
 }
\ No newline at end of file